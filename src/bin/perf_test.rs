@@ -0,0 +1,53 @@
+//! Perf-test binary: loads a saved `GameState` snapshot and measures how
+//! many headless `GameState::step` calls it can run per second.
+//!
+//! Usage: `perf_test <path-to-state.json> [seconds]`
+
+use std::env;
+use std::fs;
+use std::process;
+use std::time::{Duration, Instant};
+
+use ten::game_state::{GameState, Input};
+
+/// Fixed step size used for the benchmark, independent of wall-clock time.
+const STEP_DT: f32 = 1.0 / 60.0;
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let Some(state_path) = args.next() else {
+        eprintln!("usage: perf_test <path-to-state.json> [seconds]");
+        process::exit(1);
+    };
+
+    let duration_secs: u64 = args
+        .next()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(5);
+
+    let json = fs::read_to_string(&state_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read {}: {}", state_path, e);
+        process::exit(1);
+    });
+
+    let mut state = GameState::from_json(&json).unwrap_or_else(|e| {
+        eprintln!("Failed to parse {}: {}", state_path, e);
+        process::exit(1);
+    });
+
+    let input = Input::default();
+    let budget = Duration::from_secs(duration_secs);
+    let start = Instant::now();
+    let mut steps: u64 = 0;
+
+    while start.elapsed() < budget {
+        state.step(STEP_DT, input);
+        steps += 1;
+    }
+
+    let elapsed = start.elapsed().as_secs_f64();
+    let steps_per_sec = steps as f64 / elapsed;
+
+    println!("Ran {} steps in {:.2}s ({:.0} steps/sec)", steps, elapsed, steps_per_sec);
+}
@@ -1,21 +1,48 @@
 //! Bullet entity implementation.
 
-use crate::constants::BULLET_SPEED;
+use serde::{Deserialize, Serialize};
 
-/// Represents a bullet fired by the player.
+use crate::constants::{BULLET_SPEED, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+/// Default number of seconds a bullet survives before it retires itself,
+/// even if it never leaves the screen or hits anything.
+const DEFAULT_BULLET_LIFETIME: f32 = 3.0;
+
+/// Distinguishes who fired a bullet and what it can hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BulletKind {
+    /// Fired by the player; damages enemies.
+    PlayerShot,
+    /// Fired by an enemy; damages the player.
+    EnemyShot,
+    /// A multi-directional shot fired in a spread pattern.
+    Spread,
+}
+
+/// Represents a bullet fired by the player or an enemy.
 ///
-/// Bullets move upward at a constant speed until they either
-/// hit an enemy or move off the top of the screen.
-#[derive(Debug, Clone)]
+/// Bullets travel along a velocity vector and are retired once their
+/// lifetime runs out or they leave the screen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bullet {
     /// X position in pixels
     pub x: f32,
     /// Y position in pixels
     pub y: f32,
+    /// X velocity in pixels per second
+    pub vel_x: f32,
+    /// Y velocity in pixels per second
+    pub vel_y: f32,
+    /// Seconds remaining before this bullet retires itself
+    pub lifetime: f32,
+    /// Damage dealt to whatever this bullet hits
+    pub damage: u32,
+    /// Who fired this bullet and what it can hit
+    pub kind: BulletKind,
 }
 
 impl Bullet {
-    /// Create a new bullet at the specified position.
+    /// Create a new player bullet travelling straight up at `BULLET_SPEED`.
     ///
     /// # Arguments
     ///
@@ -23,23 +50,158 @@ impl Bullet {
     /// * `y` - Initial Y coordinate
     #[must_use]
     pub fn new(x: f32, y: f32) -> Self {
-        log::debug!("Creating bullet at ({}, {})", x, y);
-        Self { x, y }
+        Self::with_kind(
+            x,
+            y,
+            0.0,
+            -BULLET_SPEED,
+            DEFAULT_BULLET_LIFETIME,
+            1,
+            BulletKind::PlayerShot,
+        )
+    }
+
+    /// Create a new enemy bullet travelling straight down at `BULLET_SPEED`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Initial X coordinate
+    /// * `y` - Initial Y coordinate
+    #[must_use]
+    pub fn enemy_shot(x: f32, y: f32) -> Self {
+        Self::with_kind(
+            x,
+            y,
+            0.0,
+            BULLET_SPEED,
+            DEFAULT_BULLET_LIFETIME,
+            1,
+            BulletKind::EnemyShot,
+        )
     }
 
-    /// Update bullet position based on delta time.
+    /// Create a bullet with an explicit velocity, lifetime, damage and kind.
+    ///
+    /// This is the constructor enemy fire, angled shots, and other weapons
+    /// use instead of the plain straight-up [`Bullet::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Initial X coordinate
+    /// * `y` - Initial Y coordinate
+    /// * `vel_x` - X velocity in pixels per second
+    /// * `vel_y` - Y velocity in pixels per second
+    /// * `lifetime` - Seconds before the bullet retires itself
+    /// * `damage` - Damage dealt on hit
+    /// * `kind` - Who fired the bullet and what it can hit
+    #[must_use]
+    pub fn with_kind(
+        x: f32,
+        y: f32,
+        vel_x: f32,
+        vel_y: f32,
+        lifetime: f32,
+        damage: u32,
+        kind: BulletKind,
+    ) -> Self {
+        log::debug!("Creating {:?} bullet at ({}, {})", kind, x, y);
+        Self {
+            x,
+            y,
+            vel_x,
+            vel_y,
+            lifetime,
+            damage,
+            kind,
+        }
+    }
+
+    /// Update bullet position based on delta time and count down its lifetime.
     ///
     /// # Arguments
     ///
     /// * `dt` - Delta time in seconds
     pub fn update(&mut self, dt: f32) {
-        self.y -= BULLET_SPEED * dt;
+        self.x += self.vel_x * dt;
+        self.y += self.vel_y * dt;
+        self.lifetime -= dt;
     }
 
-    /// Check if bullet has moved off the top of the screen.
+    /// Check if bullet has moved off any edge of the screen.
     #[must_use]
     pub fn is_out_of_bounds(&self) -> bool {
-        self.y < 0.0
+        self.y < 0.0 || self.y > SCREEN_HEIGHT || self.x < 0.0 || self.x > SCREEN_WIDTH
+    }
+
+    /// Check if this bullet's lifetime has run out.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.lifetime <= 0.0
+    }
+
+    /// Check if this bullet should be retired: either its lifetime ran out
+    /// or it left the screen.
+    #[must_use]
+    pub fn is_retired(&self) -> bool {
+        self.is_expired() || self.is_out_of_bounds()
+    }
+}
+
+/// Owns and centrally ticks every in-flight bullet, whether fired by the
+/// player or an enemy.
+///
+/// Modeled on doukutsu-rs's bullet manager: callers `spawn` bullets into it
+/// and call `tick` once per frame, and the manager handles advancing
+/// positions, counting down lifetimes, and retiring anything that expires or
+/// leaves the screen.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulletManager {
+    bullets: Vec<Bullet>,
+}
+
+impl BulletManager {
+    /// Create an empty bullet manager.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            bullets: Vec::new(),
+        }
+    }
+
+    /// Add a bullet to the manager.
+    pub fn spawn(&mut self, bullet: Bullet) {
+        log::debug!("Spawning {:?} bullet, {} in flight", bullet.kind, self.bullets.len() + 1);
+        self.bullets.push(bullet);
+    }
+
+    /// Advance every bullet by `dt` and drop any that retired this frame.
+    pub fn tick(&mut self, dt: f32) {
+        for bullet in &mut self.bullets {
+            bullet.update(dt);
+        }
+        self.bullets.retain(|bullet| !bullet.is_retired());
+    }
+
+    /// Number of currently in-flight bullets of the given kind.
+    ///
+    /// Useful for rate-limiting fire rate, e.g. capping how many `PlayerShot`
+    /// bullets the player can have on screen at once, or how many enemies
+    /// are allowed to fire simultaneously.
+    #[must_use]
+    pub fn count(&self, kind: BulletKind) -> usize {
+        self.bullets.iter().filter(|bullet| bullet.kind == kind).count()
+    }
+
+    /// All currently in-flight bullets.
+    #[must_use]
+    pub fn bullets(&self) -> &[Bullet] {
+        &self.bullets
+    }
+
+    /// Mutable access to all currently in-flight bullets, e.g. for collision
+    /// processing that needs to remove bullets that hit something.
+    pub fn bullets_mut(&mut self) -> &mut Vec<Bullet> {
+        &mut self.bullets
     }
 }
 
@@ -58,4 +220,84 @@ mod tests {
         let bullet = Bullet::new(100.0, 100.0);
         assert!(!bullet.is_out_of_bounds());
     }
+
+    #[test]
+    fn test_player_shot_travels_up() {
+        let mut bullet = Bullet::new(100.0, 100.0);
+        bullet.update(1.0);
+        assert_eq!(bullet.x, 100.0);
+        assert_eq!(bullet.y, 100.0 - BULLET_SPEED);
+    }
+
+    #[test]
+    fn test_enemy_shot_travels_down() {
+        let mut bullet = Bullet::enemy_shot(100.0, 100.0);
+        bullet.update(1.0);
+        assert_eq!(bullet.x, 100.0);
+        assert_eq!(bullet.y, 100.0 + BULLET_SPEED);
+        assert_eq!(bullet.kind, BulletKind::EnemyShot);
+    }
+
+    #[test]
+    fn test_angled_bullet_travels_diagonally() {
+        let mut bullet = Bullet::with_kind(0.0, 0.0, 10.0, 20.0, 1.0, 1, BulletKind::Spread);
+        bullet.update(0.5);
+        assert_eq!(bullet.x, 5.0);
+        assert_eq!(bullet.y, 10.0);
+    }
+
+    #[test]
+    fn test_bullet_expires_after_lifetime() {
+        let mut bullet = Bullet::with_kind(100.0, 100.0, 0.0, 0.0, 1.0, 1, BulletKind::EnemyShot);
+        assert!(!bullet.is_expired());
+        bullet.update(0.6);
+        assert!(!bullet.is_expired());
+        bullet.update(0.6);
+        assert!(bullet.is_expired());
+        assert!(bullet.is_retired());
+    }
+
+    #[test]
+    fn test_bullet_manager_ticks_and_retires() {
+        let mut manager = BulletManager::new();
+        manager.spawn(Bullet::new(100.0, 10.0));
+        manager.spawn(Bullet::with_kind(
+            200.0,
+            100.0,
+            0.0,
+            100.0,
+            1.0,
+            1,
+            BulletKind::EnemyShot,
+        ));
+
+        assert_eq!(manager.count(BulletKind::PlayerShot), 1);
+        assert_eq!(manager.count(BulletKind::EnemyShot), 1);
+
+        // A full second moves the player shot off the top of the screen
+        // and burns through the enemy shot's one-second lifetime.
+        manager.tick(1.0);
+
+        assert_eq!(manager.bullets().len(), 0);
+    }
+
+    #[test]
+    fn test_bullet_manager_count_by_kind() {
+        let mut manager = BulletManager::new();
+        manager.spawn(Bullet::new(100.0, 500.0));
+        manager.spawn(Bullet::new(150.0, 500.0));
+        manager.spawn(Bullet::with_kind(
+            200.0,
+            50.0,
+            0.0,
+            50.0,
+            5.0,
+            1,
+            BulletKind::EnemyShot,
+        ));
+
+        assert_eq!(manager.count(BulletKind::PlayerShot), 2);
+        assert_eq!(manager.count(BulletKind::EnemyShot), 1);
+        assert_eq!(manager.count(BulletKind::Spread), 0);
+    }
 }
@@ -1,9 +1,11 @@
 //! Enemy entity implementation.
 
-use crate::constants::{DEFENDER_LINE, SCREEN_HEIGHT};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{DEFENDER_LINE, SCREEN_HEIGHT, SCREEN_WIDTH};
 
 /// Enemy type determines behavior, appearance, health, and point value.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EnemyType {
     /// Standard enemy - 1 hit, normal speed, 10 points
     Standard,
@@ -48,13 +50,41 @@ impl EnemyType {
             Self::Swooper => 30,
         }
     }
+
+    /// Recover the `EnemyType` worth the given number of points, the inverse
+    /// of [`EnemyType::points`]. Used to rebuild an `EnemyType` from the
+    /// `(x, y, points)` tuples [`crate::systems::process_collisions`] returns,
+    /// without changing that function's signature.
+    #[must_use]
+    pub const fn from_points(points: u32) -> Option<Self> {
+        match points {
+            10 => Some(Self::Standard),
+            20 => Some(Self::Fast),
+            50 => Some(Self::Tank),
+            30 => Some(Self::Swooper),
+            _ => None,
+        }
+    }
+
+    /// Get the relative "mass" of the explosion this enemy type produces
+    /// when destroyed. Scales blast radius, damage, and debris count -
+    /// a Tank's explosion is the biggest of the bunch.
+    #[must_use]
+    pub const fn explosion_mass(self) -> f32 {
+        match self {
+            Self::Standard => 1.0,
+            Self::Fast => 1.0,
+            Self::Tank => 3.0,
+            Self::Swooper => 1.0,
+        }
+    }
 }
 
 /// Represents an enemy in the game.
 ///
 /// Enemies move horizontally across the screen in their own direction,
 /// drop down when they hit the edge, and trigger game over if they reach the defender line.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enemy {
     /// X position in pixels
     pub x: f32,
@@ -101,6 +131,27 @@ impl Enemy {
         self.x += self.direction * speed * dt;
     }
 
+    /// Reverse direction and drop down a row if this enemy has moved past
+    /// either horizontal screen edge, clamping it back onto the screen.
+    ///
+    /// This is what makes the classic side-to-side, step-down-at-the-wall
+    /// movement work: call it after [`Enemy::update`] each frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `drop_distance` - How far to descend when bouncing off an edge
+    pub fn bounce_off_screen_edges(&mut self, drop_distance: f32) {
+        if self.x < 0.0 {
+            self.x = 0.0;
+            self.direction = 1.0;
+            self.y += drop_distance;
+        } else if self.x > SCREEN_WIDTH {
+            self.x = SCREEN_WIDTH;
+            self.direction = -1.0;
+            self.y += drop_distance;
+        }
+    }
+
     /// Damage the enemy by reducing health by 1.
     /// Returns true if enemy is destroyed (health reaches 0).
     #[must_use]
@@ -195,4 +246,59 @@ mod tests {
         assert_eq!(EnemyType::Tank.points(), 50);
         assert_eq!(EnemyType::Swooper.points(), 30);
     }
+
+    #[test]
+    fn test_bounce_off_right_edge_reverses_and_drops() {
+        let mut enemy = Enemy::new(SCREEN_WIDTH + 5.0, 100.0, 1.0, EnemyType::Standard);
+        enemy.bounce_off_screen_edges(20.0);
+
+        assert_eq!(enemy.x, SCREEN_WIDTH);
+        assert_eq!(enemy.direction, -1.0);
+        assert_eq!(enemy.y, 120.0);
+    }
+
+    #[test]
+    fn test_bounce_off_left_edge_reverses_and_drops() {
+        let mut enemy = Enemy::new(-5.0, 100.0, -1.0, EnemyType::Standard);
+        enemy.bounce_off_screen_edges(20.0);
+
+        assert_eq!(enemy.x, 0.0);
+        assert_eq!(enemy.direction, 1.0);
+        assert_eq!(enemy.y, 120.0);
+    }
+
+    #[test]
+    fn test_bounce_is_a_no_op_within_bounds() {
+        let mut enemy = Enemy::new(100.0, 100.0, 1.0, EnemyType::Standard);
+        enemy.bounce_off_screen_edges(20.0);
+
+        assert_eq!(enemy.x, 100.0);
+        assert_eq!(enemy.y, 100.0);
+        assert_eq!(enemy.direction, 1.0);
+    }
+
+    #[test]
+    fn test_from_points_round_trips_with_points() {
+        for enemy_type in [
+            EnemyType::Standard,
+            EnemyType::Fast,
+            EnemyType::Tank,
+            EnemyType::Swooper,
+        ] {
+            assert_eq!(EnemyType::from_points(enemy_type.points()), Some(enemy_type));
+        }
+    }
+
+    #[test]
+    fn test_from_points_rejects_unknown_values() {
+        assert_eq!(EnemyType::from_points(999), None);
+    }
+
+    #[test]
+    fn test_explosion_mass() {
+        assert_eq!(EnemyType::Standard.explosion_mass(), 1.0);
+        assert_eq!(EnemyType::Fast.explosion_mass(), 1.0);
+        assert_eq!(EnemyType::Tank.explosion_mass(), 3.0);
+        assert_eq!(EnemyType::Swooper.explosion_mass(), 1.0);
+    }
 }
@@ -6,6 +6,6 @@ pub mod bullet;
 pub mod enemy;
 pub mod player;
 
-pub use bullet::Bullet;
-pub use enemy::Enemy;
+pub use bullet::{Bullet, BulletKind, BulletManager};
+pub use enemy::{Enemy, EnemyType};
 pub use player::Player;
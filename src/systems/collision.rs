@@ -1,7 +1,9 @@
 //! Collision detection system.
 
+use std::collections::HashMap;
+
 use crate::constants::COLLISION_RADIUS;
-use crate::entities::{Bullet, Enemy};
+use crate::entities::{Bullet, BulletKind, Enemy, Player};
 
 /// Check if a bullet collides with an enemy using circle collision.
 ///
@@ -20,11 +22,51 @@ pub fn check_collision(bullet: &Bullet, enemy: &Enemy) -> bool {
     (dx * dx + dy * dy).sqrt() < COLLISION_RADIUS
 }
 
+/// Map a world position to the spatial-hash cell that contains it.
+fn cell_of(x: f32, y: f32, cell_size: f32) -> (i32, i32) {
+    ((x / cell_size).floor() as i32, (y / cell_size).floor() as i32)
+}
+
+/// Build a uniform spatial hash of bullet indices keyed by grid cell.
+///
+/// Cells are sized `COLLISION_RADIUS * 2` so that an enemy only ever needs to
+/// look at its own cell and the 8 surrounding cells to find every bullet it
+/// could possibly be touching. Indices within a bucket stay in ascending
+/// (insertion) order, matching the order bullets were originally scanned in.
+///
+/// Only bullets that can hurt an enemy (i.e. not `EnemyShot`) are indexed, so
+/// enemy return fire passes straight through this pass untouched.
+fn build_bullet_grid(bullets: &[Bullet], cell_size: f32) -> HashMap<(i32, i32), Vec<usize>> {
+    let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+    for (idx, bullet) in bullets.iter().enumerate() {
+        if bullet.kind == BulletKind::EnemyShot {
+            continue;
+        }
+        grid.entry(cell_of(bullet.x, bullet.y, cell_size))
+            .or_default()
+            .push(idx);
+    }
+    grid
+}
+
 /// Process collisions between bullets and enemies.
 ///
 /// Damages enemies hit by bullets (reduces health), removes bullets that hit,
 /// and returns positions and points for destroyed enemies.
 ///
+/// Rather than comparing every enemy against every bullet, bullets are
+/// bucketed into a uniform spatial-hash grid (cell size `COLLISION_RADIUS * 2`)
+/// once per frame. Each enemy then only tests the handful of bullets sharing
+/// its cell or one of its 8 neighbors, instead of the full bullet list,
+/// turning the per-frame cost from `O(enemies * bullets)` into roughly
+/// `O(enemies + bullets)`. The matching rule is unchanged: an enemy is hit by
+/// whichever colliding bullet has the lowest index (one bullet per enemy per
+/// frame), and destroyed-enemy semantics are identical to before.
+///
+/// Only `PlayerShot` and `Spread` bullets can damage enemies; `EnemyShot`
+/// bullets pass through untouched here and are instead checked against the
+/// player by [`process_enemy_fire`].
+///
 /// # Arguments
 ///
 /// * `enemies` - Mutable vector of enemies to check
@@ -37,32 +79,47 @@ pub fn process_collisions(
     enemies: &mut Vec<Enemy>,
     bullets: &mut Vec<Bullet>,
 ) -> Vec<(f32, f32, u32)> {
+    let cell_size = COLLISION_RADIUS * 2.0;
+    let grid = build_bullet_grid(bullets, cell_size);
+
     let mut destroyed_info = Vec::new();
     let mut bullets_to_remove = Vec::new();
 
-    // Process each enemy for collisions
     let mut i = 0;
     while i < enemies.len() {
-        let mut hit = false;
+        let (cell_x, cell_y) = cell_of(enemies[i].x, enemies[i].y, cell_size);
 
-        // Check if any bullet hits this enemy
-        for (bullet_idx, bullet) in bullets.iter().enumerate() {
-            if check_collision(bullet, &enemies[i]) {
-                // Damage the enemy
-                let destroyed = enemies[i].take_damage();
-                bullets_to_remove.push(bullet_idx);
-                hit = true;
-
-                // If enemy is destroyed, store info for explosion and score
-                if destroyed {
-                    destroyed_info.push((
-                        enemies[i].x,
-                        enemies[i].y,
-                        enemies[i].enemy_type.points(),
-                    ));
+        // Find the lowest-indexed colliding bullet among the 9 neighboring cells.
+        let mut hit_bullet = None;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                let Some(candidates) = grid.get(&(cell_x + dx, cell_y + dy)) else {
+                    continue;
+                };
+                for &bullet_idx in candidates {
+                    let is_lower_index = match hit_bullet {
+                        Some(current) => bullet_idx < current,
+                        None => true,
+                    };
+                    if is_lower_index && check_collision(&bullets[bullet_idx], &enemies[i]) {
+                        hit_bullet = Some(bullet_idx);
+                    }
                 }
+            }
+        }
 
-                break; // One bullet per enemy per frame
+        let mut hit = false;
+        if let Some(bullet_idx) = hit_bullet {
+            let destroyed = enemies[i].take_damage();
+            bullets_to_remove.push(bullet_idx);
+            hit = true;
+
+            if destroyed {
+                destroyed_info.push((
+                    enemies[i].x,
+                    enemies[i].y,
+                    enemies[i].enemy_type.points(),
+                ));
             }
         }
 
@@ -92,12 +149,116 @@ pub fn process_collisions(
     destroyed_info
 }
 
+/// Check enemy return fire against the player.
+///
+/// Mirrors [`process_collisions`] but in the other direction: only
+/// `EnemyShot` bullets are considered, the first one (by bullet index) that
+/// collides with the player damages them and is removed, and the rest of the
+/// enemy fire is left alone to keep travelling.
+///
+/// # Arguments
+///
+/// * `player` - The player to check against
+/// * `bullets` - Mutable vector of bullets to check against
+///
+/// # Returns
+///
+/// `true` if the player was hit this frame
+pub fn process_enemy_fire(player: &mut Player, bullets: &mut Vec<Bullet>) -> bool {
+    let hit_bullet = bullets.iter().position(|bullet| {
+        bullet.kind == BulletKind::EnemyShot && check_player_collision(bullet, player)
+    });
+
+    let Some(bullet_idx) = hit_bullet else {
+        return false;
+    };
+
+    let bullet = bullets.remove(bullet_idx);
+    player.take_damage(bullet.damage);
+    log::debug!("Player hit by enemy fire for {} damage", bullet.damage);
+
+    true
+}
+
+/// Check if a bullet collides with the player using circle collision.
+#[must_use]
+fn check_player_collision(bullet: &Bullet, player: &Player) -> bool {
+    let dx = player.x - bullet.x;
+    let dy = player.y - bullet.y;
+    (dx * dx + dy * dy).sqrt() < COLLISION_RADIUS
+}
+
+/// Brute-force reference used only to validate the spatial-hash broadphase
+/// above against randomized scenes. This is the O(enemies * bullets) path the
+/// grid replaces; it intentionally lives nowhere outside of tests.
+#[cfg(test)]
+fn process_collisions_brute_force(
+    enemies: &mut Vec<Enemy>,
+    bullets: &mut Vec<Bullet>,
+) -> Vec<(f32, f32, u32)> {
+    let mut destroyed_info = Vec::new();
+    let mut bullets_to_remove = Vec::new();
+
+    let mut i = 0;
+    while i < enemies.len() {
+        let mut hit = false;
+
+        for (bullet_idx, bullet) in bullets.iter().enumerate() {
+            if bullet.kind == BulletKind::EnemyShot {
+                continue;
+            }
+            if check_collision(bullet, &enemies[i]) {
+                let destroyed = enemies[i].take_damage();
+                bullets_to_remove.push(bullet_idx);
+                hit = true;
+
+                if destroyed {
+                    destroyed_info.push((enemies[i].x, enemies[i].y, enemies[i].enemy_type.points()));
+                }
+
+                break;
+            }
+        }
+
+        if hit && enemies[i].is_destroyed() {
+            enemies.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    bullets_to_remove.sort_unstable();
+    bullets_to_remove.dedup();
+    for &idx in bullets_to_remove.iter().rev() {
+        bullets.remove(idx);
+    }
+
+    destroyed_info
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::entities::EnemyType;
 
+    /// Small deterministic xorshift PRNG so randomized tests are reproducible
+    /// without pulling in an external `rand` dependency.
+    struct Rng(u32);
+
+    impl Rng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            self.0
+        }
+
+        fn next_f32(&mut self, max: f32) -> f32 {
+            (self.next_u32() % 10_000) as f32 / 10_000.0 * max
+        }
+    }
+
     #[test]
     fn test_bullet_collision_detection() {
         let enemy = Enemy::new(100.0, 200.0, 1.0, EnemyType::Standard);
@@ -216,4 +377,49 @@ mod tests {
         assert!(points.contains(&20));
         assert!(points.contains(&30));
     }
+
+    /// The grid broadphase must destroy the same enemies and consume the same
+    /// bullets as the brute-force O(enemies * bullets) path on a randomized
+    /// scene, not just on the hand-picked cases above.
+    #[test]
+    fn test_broadphase_matches_brute_force_randomized() {
+        let mut rng = Rng(0x1234_5678);
+
+        for _ in 0..20 {
+            let enemy_count = 1 + (rng.next_u32() % 150) as usize;
+            let bullet_count = 1 + (rng.next_u32() % 60) as usize;
+
+            let mut enemies_grid = Vec::with_capacity(enemy_count);
+            let mut enemies_brute = Vec::with_capacity(enemy_count);
+            for _ in 0..enemy_count {
+                let x = rng.next_f32(800.0);
+                let y = rng.next_f32(600.0);
+                let enemy_type = match rng.next_u32() % 4 {
+                    0 => EnemyType::Standard,
+                    1 => EnemyType::Fast,
+                    2 => EnemyType::Tank,
+                    _ => EnemyType::Swooper,
+                };
+                enemies_grid.push(Enemy::new(x, y, 1.0, enemy_type));
+                enemies_brute.push(Enemy::new(x, y, 1.0, enemy_type));
+            }
+
+            let mut bullets_grid = Vec::with_capacity(bullet_count);
+            let mut bullets_brute = Vec::with_capacity(bullet_count);
+            for _ in 0..bullet_count {
+                let x = rng.next_f32(800.0);
+                let y = rng.next_f32(600.0);
+                bullets_grid.push(Bullet::new(x, y));
+                bullets_brute.push(Bullet::new(x, y));
+            }
+
+            let destroyed_grid = process_collisions(&mut enemies_grid, &mut bullets_grid);
+            let destroyed_brute =
+                process_collisions_brute_force(&mut enemies_brute, &mut bullets_brute);
+
+            assert_eq!(destroyed_grid, destroyed_brute);
+            assert_eq!(enemies_grid.len(), enemies_brute.len());
+            assert_eq!(bullets_grid.len(), bullets_brute.len());
+        }
+    }
 }
@@ -0,0 +1,289 @@
+//! Explosion and debris system.
+//!
+//! Inspired by the Quake `func_explosive` model: a destroyed enemy emits an
+//! explosion whose mass scales with its `EnemyType`, and any enemy caught in
+//! the blast radius can be destroyed in turn, chaining into its own
+//! explosion. The chain is processed until the queue drains.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Enemy, EnemyType};
+
+/// Blast radius, in pixels, of a mass-1.0 explosion.
+const BASE_EXPLOSION_RADIUS: f32 = 40.0;
+
+/// Damage dealt by a mass-1.0 explosion (in `Enemy::take_damage` hits).
+const BASE_EXPLOSION_DAMAGE: u32 = 1;
+
+/// Debris chunks spawned by a mass-1.0 explosion.
+const BASE_DEBRIS_COUNT: usize = 4;
+
+/// Speed, in pixels per second, debris is flung outward at.
+const DEBRIS_SPEED: f32 = 80.0;
+
+/// Seconds a debris chunk lives before fading out.
+const DEBRIS_LIFETIME: f32 = 0.5;
+
+/// A single explosion: a center, a blast radius, and how much damage it
+/// deals to anything caught inside that radius.
+#[derive(Debug, Clone, Copy)]
+pub struct Explosion {
+    /// X position of the blast center
+    pub x: f32,
+    /// Y position of the blast center
+    pub y: f32,
+    /// Blast radius in pixels
+    pub radius: f32,
+    /// Damage dealt (in `Enemy::take_damage` hits) to anything in range
+    pub damage: u32,
+}
+
+impl Explosion {
+    /// Create an explosion with an explicit radius and damage.
+    #[must_use]
+    pub fn new(x: f32, y: f32, radius: f32, damage: u32) -> Self {
+        Self {
+            x,
+            y,
+            radius,
+            damage,
+        }
+    }
+
+    /// Create the explosion a destroyed enemy of the given type emits,
+    /// scaling radius and damage by its `explosion_mass`.
+    #[must_use]
+    pub fn for_enemy_type(x: f32, y: f32, enemy_type: EnemyType) -> Self {
+        let mass = enemy_type.explosion_mass();
+        let radius = BASE_EXPLOSION_RADIUS * mass;
+        let damage = (BASE_EXPLOSION_DAMAGE as f32 * mass).round() as u32;
+        Self::new(x, y, radius, damage.max(1))
+    }
+
+    /// The mass this explosion was built from, inferred from its radius.
+    fn mass(&self) -> f32 {
+        self.radius / BASE_EXPLOSION_RADIUS
+    }
+}
+
+/// A short-lived debris chunk flung outward from an explosion, purely for
+/// visual effect - it renders and fades, with no gameplay impact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Debris {
+    /// X position in pixels
+    pub x: f32,
+    /// Y position in pixels
+    pub y: f32,
+    /// X velocity in pixels per second
+    pub vel_x: f32,
+    /// Y velocity in pixels per second
+    pub vel_y: f32,
+    /// Seconds remaining before this chunk fades out
+    pub lifetime: f32,
+}
+
+impl Debris {
+    /// Create a new debris chunk at the specified position and velocity.
+    #[must_use]
+    pub fn new(x: f32, y: f32, vel_x: f32, vel_y: f32, lifetime: f32) -> Self {
+        Self {
+            x,
+            y,
+            vel_x,
+            vel_y,
+            lifetime,
+        }
+    }
+
+    /// Update debris position based on delta time and count down its lifetime.
+    pub fn update(&mut self, dt: f32) {
+        self.x += self.vel_x * dt;
+        self.y += self.vel_y * dt;
+        self.lifetime -= dt;
+    }
+
+    /// Check if this debris chunk has finished fading out.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.lifetime <= 0.0
+    }
+}
+
+/// Spawn debris radiating outward from an explosion, with a count
+/// proportional to the explosion's mass.
+fn spawn_debris(explosion: &Explosion) -> Vec<Debris> {
+    let count = (BASE_DEBRIS_COUNT as f32 * explosion.mass()).round() as usize;
+
+    (0..count)
+        .map(|i| {
+            let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
+            Debris::new(
+                explosion.x,
+                explosion.y,
+                angle.cos() * DEBRIS_SPEED,
+                angle.sin() * DEBRIS_SPEED,
+                DEBRIS_LIFETIME,
+            )
+        })
+        .collect()
+}
+
+/// Resolve a queue of explosions against a list of enemies, chaining into
+/// secondary explosions whenever a blast destroys another enemy.
+///
+/// Each enemy can only ever be destroyed (and thus explode) once, because a
+/// destroyed enemy is removed from `enemies` immediately - it can no longer
+/// be found by a later explosion in the queue. That bounds the chain to at
+/// most `enemies.len()` secondary blasts, so the queue is guaranteed to
+/// drain.
+///
+/// # Arguments
+///
+/// * `enemies` - Mutable vector of enemies that can be caught in the blast
+/// * `initial` - The explosion(s) that start the chain, e.g. from enemies
+///   already destroyed by `process_collisions`
+///
+/// # Returns
+///
+/// A tuple of `(destroyed, debris)`: every enemy destroyed by the chain
+/// (including chained ones, for scoring) as `(x, y, points)`, and every
+/// debris chunk spawned along the way.
+pub fn process_explosions(
+    enemies: &mut Vec<Enemy>,
+    initial: Vec<Explosion>,
+) -> (Vec<(f32, f32, u32)>, Vec<Debris>) {
+    let mut destroyed_info = Vec::new();
+    let mut debris = Vec::new();
+    let mut queue: VecDeque<Explosion> = initial.into_iter().collect();
+
+    while let Some(explosion) = queue.pop_front() {
+        debris.extend(spawn_debris(&explosion));
+
+        let mut i = 0;
+        while i < enemies.len() {
+            let dx = enemies[i].x - explosion.x;
+            let dy = enemies[i].y - explosion.y;
+            let in_blast = (dx * dx + dy * dy).sqrt() <= explosion.radius;
+
+            if in_blast {
+                let mut destroyed = false;
+                for _ in 0..explosion.damage {
+                    if enemies[i].take_damage() {
+                        destroyed = true;
+                        break;
+                    }
+                }
+
+                if destroyed {
+                    let (x, y, enemy_type) = (enemies[i].x, enemies[i].y, enemies[i].enemy_type);
+                    destroyed_info.push((x, y, enemy_type.points()));
+                    queue.push_back(Explosion::for_enemy_type(x, y, enemy_type));
+                    enemies.remove(i);
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+    }
+
+    if !destroyed_info.is_empty() {
+        log::debug!(
+            "Explosion chain destroyed {} enemies and spawned {} debris chunks",
+            destroyed_info.len(),
+            debris.len()
+        );
+    }
+
+    (destroyed_info, debris)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::entities::EnemyType;
+
+    #[test]
+    fn test_debris_fades_out() {
+        let mut debris = Debris::new(0.0, 0.0, 10.0, 0.0, 0.5);
+        assert!(!debris.is_expired());
+        debris.update(0.3);
+        assert!(!debris.is_expired());
+        debris.update(0.3);
+        assert!(debris.is_expired());
+    }
+
+    #[test]
+    fn test_tank_explosion_is_bigger_than_standard() {
+        let tank_blast = Explosion::for_enemy_type(0.0, 0.0, EnemyType::Tank);
+        let standard_blast = Explosion::for_enemy_type(0.0, 0.0, EnemyType::Standard);
+
+        assert!(tank_blast.radius > standard_blast.radius);
+        assert!(tank_blast.damage > standard_blast.damage);
+    }
+
+    #[test]
+    fn test_explosion_destroys_single_enemy_in_radius() {
+        let mut enemies = vec![Enemy::new(10.0, 0.0, 1.0, EnemyType::Standard)];
+        let initial = vec![Explosion::for_enemy_type(0.0, 0.0, EnemyType::Standard)];
+
+        let (destroyed, debris) = process_explosions(&mut enemies, initial);
+
+        assert_eq!(destroyed.len(), 1);
+        assert!(enemies.is_empty());
+        assert!(!debris.is_empty());
+    }
+
+    #[test]
+    fn test_explosion_does_not_reach_distant_enemy() {
+        let mut enemies = vec![Enemy::new(500.0, 0.0, 1.0, EnemyType::Standard)];
+        let initial = vec![Explosion::for_enemy_type(0.0, 0.0, EnemyType::Tank)];
+
+        let (destroyed, _debris) = process_explosions(&mut enemies, initial);
+
+        assert_eq!(destroyed.len(), 0);
+        assert_eq!(enemies.len(), 1);
+    }
+
+    /// A Tank's blast only directly reaches the nearest Standard enemy, but
+    /// that enemy's own explosion reaches the next one, and so on - clearing
+    /// the whole row in a chain reaction.
+    #[test]
+    fn test_tank_blast_chains_through_adjacent_standard_enemies() {
+        let mut enemies = vec![
+            Enemy::new(100.0, 0.0, 1.0, EnemyType::Standard),
+            Enemy::new(135.0, 0.0, 1.0, EnemyType::Standard),
+            Enemy::new(170.0, 0.0, 1.0, EnemyType::Standard),
+            Enemy::new(205.0, 0.0, 1.0, EnemyType::Standard),
+            Enemy::new(240.0, 0.0, 1.0, EnemyType::Standard),
+        ];
+
+        let initial = vec![Explosion::for_enemy_type(0.0, 0.0, EnemyType::Tank)];
+        let (destroyed, debris) = process_explosions(&mut enemies, initial);
+
+        assert_eq!(destroyed.len(), 5);
+        assert!(enemies.is_empty());
+        assert!(!debris.is_empty());
+
+        let points: Vec<u32> = destroyed.iter().map(|(_, _, p)| *p).collect();
+        assert!(points.iter().all(|&p| p == EnemyType::Standard.points()));
+    }
+
+    #[test]
+    fn test_chain_does_not_cross_a_gap_too_wide_for_the_blast() {
+        let mut enemies = vec![
+            Enemy::new(30.0, 0.0, 1.0, EnemyType::Standard),
+            Enemy::new(500.0, 0.0, 1.0, EnemyType::Standard), // far outside any blast
+        ];
+
+        let initial = vec![Explosion::for_enemy_type(0.0, 0.0, EnemyType::Standard)];
+        let (destroyed, _debris) = process_explosions(&mut enemies, initial);
+
+        assert_eq!(destroyed.len(), 1);
+        assert_eq!(enemies.len(), 1);
+        assert_eq!(enemies[0].x, 500.0);
+    }
+}
@@ -0,0 +1,14 @@
+//! Game systems.
+//!
+//! Contains the gameplay systems that operate on entities: collision
+//! detection, chained explosions, wave generation, and the attract-mode AI.
+
+pub mod ai;
+pub mod collision;
+pub mod explosion;
+pub mod wave;
+
+pub use ai::AutoPlayController;
+pub use collision::{check_collision, process_collisions, process_enemy_fire};
+pub use explosion::{process_explosions, Debris, Explosion};
+pub use wave::generate_wave;
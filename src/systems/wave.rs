@@ -1,11 +1,230 @@
 //! Wave generation system.
+//!
+//! Wave layouts are data-driven: each wave is described by a
+//! [`WaveDefinition`] (formation, enemy-type mix, spacing, starting
+//! direction), loaded from the `resources/waves.json` resource so designers
+//! can tune the difficulty curve without recompiling. If that resource is
+//! missing or fails to parse, [`generate_wave`] falls back to a small
+//! procedural default so the game still has something to spawn.
 
-use crate::entities::Enemy;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Enemy, EnemyType};
+
+/// Shape the enemies in a wave are arranged into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Formation {
+    /// A simple rows x columns grid.
+    Grid,
+    /// A "V", narrowest at the center column and widening toward the edges.
+    VShape,
+    /// Columns staggered diagonally, each one starting lower than the last.
+    Diagonal,
+}
+
+/// One entry in a wave's enemy-type mix: `weight` copies of `enemy_type` are
+/// added to the pool enemies are drawn from, round-robin.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EnemyWeight {
+    /// The enemy type this entry contributes
+    pub enemy_type: EnemyType,
+    /// How many copies of `enemy_type` go into the pool, relative to the
+    /// other entries in the same mix (a weight of 0 counts as 1)
+    pub weight: u32,
+}
+
+/// Full description of a single wave: formation, size, enemy-type mix,
+/// spacing, and starting direction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveDefinition {
+    /// Arrangement the enemies are laid out in
+    pub formation: Formation,
+    /// Number of enemies per column
+    pub rows: usize,
+    /// Number of columns
+    pub columns: usize,
+    /// Weighted mix of enemy types to draw from, in order
+    pub enemy_mix: Vec<EnemyWeight>,
+    /// Starting movement direction (1.0 = right, -1.0 = left)
+    pub direction: f32,
+    /// Horizontal distance between columns, in pixels
+    pub spacing_x: f32,
+    /// Vertical distance between rows, in pixels
+    pub spacing_y: f32,
+    /// X position of the first column
+    pub origin_x: f32,
+    /// Y position of the first row
+    pub origin_y: f32,
+}
+
+/// Path to the wave-definitions resource, relative to the crate root.
+fn waves_resource_path() -> String {
+    format!("{}/resources/waves.json", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Load wave definitions from `resources/waves.json`, falling back to
+/// [`procedural_default_waves`] if the file is missing, fails to parse, or
+/// parses to an empty list (which would otherwise leave [`generate_wave`]
+/// with nothing to index into).
+fn load_wave_definitions() -> Vec<WaveDefinition> {
+    let path = waves_resource_path();
+
+    let json = match std::fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(e) => {
+            log::warn!("Could not read {}: {}; using procedural default waves", path, e);
+            return procedural_default_waves();
+        }
+    };
+
+    let definitions: Vec<WaveDefinition> = match serde_json::from_str(&json) {
+        Ok(definitions) => definitions,
+        Err(e) => {
+            log::warn!("Could not parse {}: {}; using procedural default waves", path, e);
+            return procedural_default_waves();
+        }
+    };
+
+    or_procedural_default_if_empty(definitions, &path)
+}
+
+/// Fall back to [`procedural_default_waves`] if `definitions` parsed
+/// successfully but turned out to be empty, since an empty list would
+/// otherwise leave [`generate_wave`] with nothing to index into.
+fn or_procedural_default_if_empty(definitions: Vec<WaveDefinition>, path: &str) -> Vec<WaveDefinition> {
+    if definitions.is_empty() {
+        log::warn!("{} is empty; using procedural default waves", path);
+        return procedural_default_waves();
+    }
+
+    definitions
+}
+
+/// Small built-in set of waves used when `waves.json` isn't available,
+/// escalating from an all-`Standard` grid to mixed formations with Tanks and
+/// Swoopers.
+fn procedural_default_waves() -> Vec<WaveDefinition> {
+    vec![
+        WaveDefinition {
+            formation: Formation::Grid,
+            rows: 3,
+            columns: 10,
+            enemy_mix: vec![EnemyWeight {
+                enemy_type: EnemyType::Standard,
+                weight: 1,
+            }],
+            direction: 1.0,
+            spacing_x: 60.0,
+            spacing_y: 50.0,
+            origin_x: 50.0,
+            origin_y: 100.0,
+        },
+        WaveDefinition {
+            formation: Formation::Grid,
+            rows: 4,
+            columns: 10,
+            enemy_mix: vec![
+                EnemyWeight {
+                    enemy_type: EnemyType::Standard,
+                    weight: 3,
+                },
+                EnemyWeight {
+                    enemy_type: EnemyType::Fast,
+                    weight: 1,
+                },
+            ],
+            direction: 1.0,
+            spacing_x: 60.0,
+            spacing_y: 50.0,
+            origin_x: 50.0,
+            origin_y: 100.0,
+        },
+        WaveDefinition {
+            formation: Formation::VShape,
+            rows: 2,
+            columns: 9,
+            enemy_mix: vec![
+                EnemyWeight {
+                    enemy_type: EnemyType::Standard,
+                    weight: 2,
+                },
+                EnemyWeight {
+                    enemy_type: EnemyType::Swooper,
+                    weight: 1,
+                },
+                EnemyWeight {
+                    enemy_type: EnemyType::Tank,
+                    weight: 1,
+                },
+            ],
+            direction: -1.0,
+            spacing_x: 60.0,
+            spacing_y: 40.0,
+            origin_x: 50.0,
+            origin_y: 80.0,
+        },
+    ]
+}
+
+/// Build the round-robin pool of enemy types a wave's enemies are drawn
+/// from, `weight` copies of each entry in order. An entry with weight 0
+/// still contributes one copy; an empty mix yields a single `Standard`.
+fn enemy_type_pool(enemy_mix: &[EnemyWeight]) -> Vec<EnemyType> {
+    let mut pool = Vec::new();
+    for entry in enemy_mix {
+        for _ in 0..entry.weight.max(1) {
+            pool.push(entry.enemy_type);
+        }
+    }
+
+    if pool.is_empty() {
+        pool.push(EnemyType::Standard);
+    }
+
+    pool
+}
+
+/// Compute the (x, y) position of the enemy at `(row, column)` for the given
+/// formation.
+fn position_in_formation(def: &WaveDefinition, row: usize, column: usize) -> (f32, f32) {
+    let x = def.origin_x + column as f32 * def.spacing_x;
+
+    let y = match def.formation {
+        Formation::Grid => def.origin_y + row as f32 * def.spacing_y,
+        Formation::VShape => {
+            let center = (def.columns.max(1) - 1) as f32 / 2.0;
+            let depth_from_apex = (column as f32 - center).abs();
+            def.origin_y + (depth_from_apex + row as f32) * def.spacing_y
+        }
+        Formation::Diagonal => def.origin_y + (column as f32 + row as f32) * def.spacing_y,
+    };
+
+    (x, y)
+}
+
+/// Build the enemies for a single wave definition.
+fn build_enemies(def: &WaveDefinition) -> Vec<Enemy> {
+    let pool = enemy_type_pool(&def.enemy_mix);
+    let mut enemies = Vec::with_capacity(def.rows * def.columns);
+    let mut pool_index = 0;
+
+    for column in 0..def.columns {
+        for row in 0..def.rows {
+            let enemy_type = pool[pool_index % pool.len()];
+            let (x, y) = position_in_formation(def, row, column);
+            enemies.push(Enemy::new(x, y, def.direction, enemy_type));
+            pool_index += 1;
+        }
+    }
+
+    enemies
+}
 
 /// Generate enemies for a given wave number.
 ///
-/// Each wave generates a grid of enemies with progressively more rows.
-/// The formula is: rows = 2 + wave_number, with a constant 10 columns.
+/// Looks up the matching [`WaveDefinition`] loaded from `resources/waves.json`
+/// (or the procedural fallback), clamping/looping back to the start of the
+/// list for waves beyond what's defined.
 ///
 /// # Arguments
 ///
@@ -13,36 +232,22 @@ use crate::entities::Enemy;
 ///
 /// # Returns
 ///
-/// A vector of enemies positioned in a grid formation
-///
-/// # Examples
-///
-/// ```
-/// # use ten::systems::wave::generate_wave;
-/// let wave_1 = generate_wave(1);  // 30 enemies (3 rows x 10 columns)
-/// let wave_2 = generate_wave(2);  // 40 enemies (4 rows x 10 columns)
-/// ```
+/// A vector of enemies positioned according to the wave's formation
 #[must_use]
 pub fn generate_wave(wave: u32) -> Vec<Enemy> {
-    let rows = 2 + wave as usize;
-    let columns = 10;
-    let enemy_count = rows * columns;
+    let definitions = load_wave_definitions();
+    let index = (wave.saturating_sub(1) as usize) % definitions.len();
+    let def = &definitions[index];
 
-    log::info!("Generating wave {} with {} enemies ({} rows x {} columns)",
-               wave, enemy_count, rows, columns);
+    log::info!(
+        "Generating wave {} using {:?} formation ({} rows x {} columns)",
+        wave,
+        def.formation,
+        def.rows,
+        def.columns
+    );
 
-    let mut enemies = Vec::with_capacity(enemy_count);
-
-    for i in 0..columns {
-        for j in 0..rows {
-            enemies.push(Enemy::new(
-                50.0 + i as f32 * 60.0,
-                100.0 + j as f32 * 50.0,
-            ));
-        }
-    }
-
-    enemies
+    build_enemies(def)
 }
 
 #[cfg(test)]
@@ -52,14 +257,14 @@ mod tests {
     #[test]
     fn test_generate_enemies_wave_1() {
         let enemies = generate_wave(1);
-        // Wave 1 should have 3 rows (2 + 1) and 10 columns
+        // Wave 1 is the default's grid: 3 rows x 10 columns
         assert_eq!(enemies.len(), 30);
     }
 
     #[test]
     fn test_generate_enemies_wave_2() {
         let enemies = generate_wave(2);
-        // Wave 2 should have 4 rows (2 + 2) and 10 columns
+        // Wave 2 is the default's grid: 4 rows x 10 columns
         assert_eq!(enemies.len(), 40);
     }
 
@@ -80,4 +285,53 @@ mod tests {
         assert_eq!(enemies[3].x, 110.0); // 50.0 + 60.0
         assert_eq!(enemies[3].y, 100.0);
     }
+
+    #[test]
+    fn test_wave_2_mixes_in_fast_enemies() {
+        let enemies = generate_wave(2);
+
+        let standard_count = enemies
+            .iter()
+            .filter(|e| e.enemy_type == EnemyType::Standard)
+            .count();
+        let fast_count = enemies
+            .iter()
+            .filter(|e| e.enemy_type == EnemyType::Fast)
+            .count();
+
+        assert_eq!(standard_count + fast_count, enemies.len());
+        assert!(fast_count > 0, "wave 2 should include some Fast enemies");
+    }
+
+    #[test]
+    fn test_wave_3_introduces_tanks_and_swoopers_in_a_v_shape() {
+        let enemies = generate_wave(3);
+
+        assert_eq!(enemies.len(), 18); // 2 rows x 9 columns
+
+        let has_tank = enemies.iter().any(|e| e.enemy_type == EnemyType::Tank);
+        let has_swooper = enemies.iter().any(|e| e.enemy_type == EnemyType::Swooper);
+        assert!(has_tank, "wave 3 should include at least one Tank");
+        assert!(has_swooper, "wave 3 should include at least one Swooper");
+
+        // V-shape: the center column should start higher up than the edge columns.
+        let center_column_y = position_in_formation(&procedural_default_waves()[2], 0, 4).1;
+        let edge_column_y = position_in_formation(&procedural_default_waves()[2], 0, 0).1;
+        assert!(center_column_y < edge_column_y);
+    }
+
+    #[test]
+    fn test_empty_parsed_definitions_fall_back_to_procedural_default() {
+        let resolved = or_procedural_default_if_empty(Vec::new(), "waves.json");
+        assert_eq!(resolved.len(), procedural_default_waves().len());
+    }
+
+    #[test]
+    fn test_waves_beyond_the_list_loop_back_around() {
+        let defs = procedural_default_waves();
+        let wave_1 = generate_wave(1);
+        let wave_beyond = generate_wave(1 + defs.len() as u32);
+
+        assert_eq!(wave_1.len(), wave_beyond.len());
+    }
 }